@@ -34,16 +34,6 @@ pub fn check(headers: &HeaderMap) -> Vec<SecurityIssue> {
         }
     };
 
-    // Check for unsafe-inline
-    if csp.contains("'unsafe-inline'") {
-        issues.push(SecurityIssue {
-            category: "CSP".to_string(),
-            severity: Severity::High,
-            message: "CSP allows 'unsafe-inline'".to_string(),
-            recommendation: "Remove 'unsafe-inline' and use nonces or hashes for inline scripts/styles".to_string(),
-        });
-    }
-
     // Check for unsafe-eval
     if csp.contains("'unsafe-eval'") {
         issues.push(SecurityIssue {
@@ -54,37 +44,7 @@ pub fn check(headers: &HeaderMap) -> Vec<SecurityIssue> {
         });
     }
 
-    // Check for wildcard in script-src
-    if let Some(script_src) = extract_directive(csp, "script-src") {
-        if script_src.contains(" * ") || script_src.ends_with(" *") || script_src == "*" {
-            issues.push(SecurityIssue {
-                category: "CSP".to_string(),
-                severity: Severity::Critical,
-                message: "CSP script-src allows all sources (*)".to_string(),
-                recommendation: "Restrict script-src to specific trusted domains only".to_string(),
-            });
-        }
-
-        // Check for data: in script-src
-        if script_src.contains("data:") {
-            issues.push(SecurityIssue {
-                category: "CSP".to_string(),
-                severity: Severity::High,
-                message: "CSP script-src allows 'data:' URIs".to_string(),
-                recommendation: "Remove 'data:' from script-src to prevent base64 encoded script execution".to_string(),
-            });
-        }
-
-        // Check for overly permissive https:
-        if script_src.contains("https:") && !script_src.contains("'unsafe-inline'") {
-            issues.push(SecurityIssue {
-                category: "CSP".to_string(),
-                severity: Severity::Medium,
-                message: "CSP script-src allows all HTTPS sources".to_string(),
-                recommendation: "Restrict to specific HTTPS domains instead of allowing all HTTPS".to_string(),
-            });
-        }
-    }
+    issues.extend(evaluate_script_src(csp));
 
     // Check for wildcard in default-src
     if let Some(default_src) = extract_directive(csp, "default-src") {
@@ -144,9 +104,212 @@ pub fn check(headers: &HeaderMap) -> Vec<SecurityIssue> {
         });
     }
 
+    issues.extend(check_reporting(csp, headers));
+
+    issues
+}
+
+/// Origins known to host JSONP endpoints or otherwise-executable
+/// user content that attackers can abuse to run arbitrary script despite
+/// being on an allowlist. Curated, not exhaustive; update as new bypasses
+/// are published.
+const KNOWN_BYPASSABLE_ORIGINS: &[&str] = &[
+    "*.googleapis.com",
+    "ajax.googleapis.com",
+    "*.google.com",
+    "cdnjs.cloudflare.com",
+];
+
+fn is_nonce_or_hash(token: &str) -> bool {
+    token.starts_with("'nonce-")
+        || token.starts_with("'sha256-")
+        || token.starts_with("'sha384-")
+        || token.starts_with("'sha512-")
+}
+
+fn matched_bypassable_origin(token: &str) -> Option<&'static str> {
+    KNOWN_BYPASSABLE_ORIGINS
+        .iter()
+        .find(|origin| token.contains(*origin))
+        .copied()
+}
+
+/// Evaluates `script-src` the way a browser actually enforces it, rather
+/// than flagging tokens in isolation: a nonce/hash makes `'unsafe-inline'`
+/// a harmless legacy fallback, and `'strict-dynamic'` makes host/scheme
+/// allowlists irrelevant, so those shouldn't double up as separate findings.
+fn evaluate_script_src(csp: &str) -> Vec<SecurityIssue> {
+    let mut issues = Vec::new();
+
+    let script_src = extract_directive(csp, "script-src");
+    let tokens: Vec<&str> = script_src
+        .as_deref()
+        .map(|s| s.split_whitespace().collect())
+        .unwrap_or_default();
+
+    let has_nonce_or_hash = tokens.iter().any(|t| is_nonce_or_hash(t));
+    let has_strict_dynamic = tokens.iter().any(|&t| t == "'strict-dynamic'");
+    let has_unsafe_inline = tokens.iter().any(|&t| t == "'unsafe-inline'")
+        || (script_src.is_none() && csp.contains("'unsafe-inline'"));
+
+    if has_unsafe_inline {
+        if has_nonce_or_hash {
+            issues.push(SecurityIssue {
+                category: "CSP".to_string(),
+                severity: Severity::Info,
+                message: "CSP script-src has 'unsafe-inline' alongside a nonce/hash".to_string(),
+                recommendation: "Browsers that support nonces/hashes ignore 'unsafe-inline'; it's only a harmless fallback for very old browsers and can be left in place".to_string(),
+            });
+        } else {
+            issues.push(SecurityIssue {
+                category: "CSP".to_string(),
+                severity: Severity::High,
+                message: "CSP allows 'unsafe-inline'".to_string(),
+                recommendation: "Remove 'unsafe-inline' and use nonces or hashes for inline scripts/styles".to_string(),
+            });
+        }
+    }
+
+    if let Some(script_src) = &script_src {
+        if has_strict_dynamic {
+            if !has_nonce_or_hash {
+                issues.push(SecurityIssue {
+                    category: "CSP".to_string(),
+                    severity: Severity::High,
+                    message: "CSP script-src uses 'strict-dynamic' without a nonce or hash".to_string(),
+                    recommendation: "'strict-dynamic' requires a nonce or hash on the root <script> tag to bootstrap trust; without one, scripts may fail to load or the directive has no effect".to_string(),
+                });
+            }
+            // strict-dynamic makes browsers ignore host/scheme allowlists
+            // entirely, so the wildcard/https warnings below would be noise.
+        } else {
+            if script_src.contains(" * ") || script_src.ends_with(" *") || script_src == "*" {
+                issues.push(SecurityIssue {
+                    category: "CSP".to_string(),
+                    severity: Severity::Critical,
+                    message: "CSP script-src allows all sources (*)".to_string(),
+                    recommendation: "Restrict script-src to specific trusted domains only".to_string(),
+                });
+            }
+
+            if script_src.contains("data:") {
+                issues.push(SecurityIssue {
+                    category: "CSP".to_string(),
+                    severity: Severity::High,
+                    message: "CSP script-src allows 'data:' URIs".to_string(),
+                    recommendation: "Remove 'data:' from script-src to prevent base64 encoded script execution".to_string(),
+                });
+            }
+
+            if script_src.contains("https:") && !has_unsafe_inline {
+                issues.push(SecurityIssue {
+                    category: "CSP".to_string(),
+                    severity: Severity::Medium,
+                    message: "CSP script-src allows all HTTPS sources".to_string(),
+                    recommendation: "Restrict to specific HTTPS domains instead of allowing all HTTPS".to_string(),
+                });
+            }
+
+            for token in &tokens {
+                if let Some(origin) = matched_bypassable_origin(token) {
+                    issues.push(SecurityIssue {
+                        category: "CSP".to_string(),
+                        severity: Severity::High,
+                        message: format!(
+                            "CSP script-src allowlists '{}', a known CSP-bypassable origin (JSONP/user-content CDN)",
+                            origin
+                        ),
+                        recommendation: "Remove this origin from script-src or pair it with 'strict-dynamic' and a nonce/hash so the allowlist no longer matters".to_string(),
+                    });
+                }
+            }
+        }
+    }
+
+    issues
+}
+
+/// Cross-validates a policy's `report-uri`/`report-to` directives against
+/// the sibling `Report-To` and `Reporting-Endpoints` response headers, so
+/// a policy can't silently point at a reporting group that doesn't exist.
+fn check_reporting(csp: &str, headers: &HeaderMap) -> Vec<SecurityIssue> {
+    let mut issues = Vec::new();
+
+    let report_uri = extract_directive(csp, "report-uri");
+    let report_to_group = extract_directive(csp, "report-to")
+        .and_then(|value| value.split_whitespace().next().map(|s| s.to_string()));
+    let has_reporting_endpoints_header = headers.contains_key("reporting-endpoints");
+
+    if let Some(group) = &report_to_group {
+        if !report_to_group_exists(headers, group) && !reporting_endpoints_group_exists(headers, group) {
+            issues.push(SecurityIssue {
+                category: "CSP".to_string(),
+                severity: Severity::Medium,
+                message: format!(
+                    "CSP report-to directive names group '{}' but no matching group is defined in Report-To",
+                    group
+                ),
+                recommendation: "Add a Report-To header whose 'group' matches the CSP report-to token and has at least one endpoint".to_string(),
+            });
+        }
+    }
+
+    if report_uri.is_none() && report_to_group.is_none() {
+        issues.push(SecurityIssue {
+            category: "CSP".to_string(),
+            severity: Severity::Info,
+            message: "CSP has no reporting configured (no report-uri or report-to)".to_string(),
+            recommendation: "Add a 'report-to' directive plus a Reporting-Endpoints header so violations are visible instead of silently blocked".to_string(),
+        });
+    } else if report_uri.is_some() && report_to_group.is_none() && !has_reporting_endpoints_header {
+        issues.push(SecurityIssue {
+            category: "CSP".to_string(),
+            severity: Severity::Low,
+            message: "CSP relies solely on the deprecated 'report-uri' directive".to_string(),
+            recommendation: "Add 'report-to' with a matching Reporting-Endpoints/Report-To header as a modern fallback, since 'report-uri' is being removed from browsers".to_string(),
+        });
+    }
+
     issues
 }
 
+/// Parses the `Report-To` header(s) looking for an entry whose `"group"`
+/// matches `group` and whose `"endpoints"` array is non-empty. `Report-To`
+/// may be sent as multiple header lines, one JSON object each.
+fn report_to_group_exists(headers: &HeaderMap, group: &str) -> bool {
+    headers
+        .get_all("report-to")
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .filter_map(|v| serde_json::from_str::<serde_json::Value>(v).ok())
+        .any(|entry| {
+            entry.get("group").and_then(|g| g.as_str()) == Some(group)
+                && entry
+                    .get("endpoints")
+                    .and_then(|e| e.as_array())
+                    .map(|a| !a.is_empty())
+                    .unwrap_or(false)
+        })
+}
+
+/// Parses the `Reporting-Endpoints` structured-field header (a comma-list
+/// of `name="url"` members) looking for an entry named `group`.
+fn reporting_endpoints_group_exists(headers: &HeaderMap, group: &str) -> bool {
+    headers
+        .get_all("reporting-endpoints")
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .any(|value| {
+            value.split(',').any(|member| {
+                member
+                    .trim()
+                    .split_once('=')
+                    .map(|(name, _)| name.trim() == group)
+                    .unwrap_or(false)
+            })
+        })
+}
+
 fn extract_directive(csp: &str, directive: &str) -> Option<String> {
     let pattern = format!(r"{}\s+([^;]+)", regex::escape(directive));
     let re = Regex::new(&pattern).ok()?;
@@ -174,4 +337,110 @@ mod tests {
             Some("'self'".to_string())
         );
     }
+
+    #[test]
+    fn test_unsafe_inline_suppressed_with_nonce() {
+        let csp = "script-src 'self' 'unsafe-inline' 'nonce-abc123'";
+        let issues = evaluate_script_src(csp);
+        assert!(!issues
+            .iter()
+            .any(|i| matches!(i.severity, Severity::High) && i.message.contains("'unsafe-inline'")));
+        assert!(issues.iter().any(|i| i.message.contains("harmless")
+            || i.message.contains("nonce/hash")));
+    }
+
+    #[test]
+    fn test_unsafe_inline_without_nonce_stays_high() {
+        let csp = "script-src 'self' 'unsafe-inline'";
+        let issues = evaluate_script_src(csp);
+        assert!(issues
+            .iter()
+            .any(|i| matches!(i.severity, Severity::High) && i.message.contains("'unsafe-inline'")));
+    }
+
+    #[test]
+    fn test_strict_dynamic_suppresses_host_allowlist_warnings() {
+        let csp = "script-src 'strict-dynamic' 'nonce-abc123' https: *.example.com";
+        let issues = evaluate_script_src(csp);
+        assert!(!issues.iter().any(|i| i.message.contains("all HTTPS sources")));
+    }
+
+    #[test]
+    fn test_strict_dynamic_without_nonce_flagged() {
+        let csp = "script-src 'strict-dynamic'";
+        let issues = evaluate_script_src(csp);
+        assert!(issues
+            .iter()
+            .any(|i| matches!(i.severity, Severity::High) && i.message.contains("strict-dynamic")));
+    }
+
+    #[test]
+    fn test_known_bypassable_origin_flagged() {
+        let csp = "script-src 'self' ajax.googleapis.com";
+        let issues = evaluate_script_src(csp);
+        assert!(issues
+            .iter()
+            .any(|i| matches!(i.severity, Severity::High) && i.message.contains("ajax.googleapis.com")));
+    }
+
+    #[test]
+    fn test_bypassable_origin_suppressed_with_strict_dynamic() {
+        let csp = "script-src 'strict-dynamic' 'nonce-abc123' ajax.googleapis.com";
+        let issues = evaluate_script_src(csp);
+        assert!(!issues.iter().any(|i| i.message.contains("bypassable")));
+    }
+
+    #[test]
+    fn test_no_reporting_configured() {
+        let issues = check_reporting("default-src 'self'", &HeaderMap::new());
+        assert!(issues
+            .iter()
+            .any(|i| i.message.contains("no reporting configured")));
+    }
+
+    #[test]
+    fn test_report_uri_only_is_low() {
+        let issues = check_reporting("default-src 'self'; report-uri /csp-report", &HeaderMap::new());
+        assert!(issues
+            .iter()
+            .any(|i| matches!(i.severity, Severity::Low) && i.message.contains("deprecated")));
+    }
+
+    #[test]
+    fn test_report_to_group_undefined() {
+        let issues = check_reporting("default-src 'self'; report-to csp-endpoint", &HeaderMap::new());
+        assert!(issues
+            .iter()
+            .any(|i| matches!(i.severity, Severity::Medium) && i.message.contains("csp-endpoint")));
+    }
+
+    #[test]
+    fn test_report_to_group_defined() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "report-to",
+            reqwest::header::HeaderValue::from_static(
+                r#"{"group":"csp-endpoint","max_age":10886400,"endpoints":[{"url":"https://example.com/reports"}]}"#,
+            ),
+        );
+        let issues = check_reporting("default-src 'self'; report-to csp-endpoint", &headers);
+        assert!(!issues
+            .iter()
+            .any(|i| i.message.contains("no matching group")));
+    }
+
+    #[test]
+    fn test_report_to_group_defined_via_reporting_endpoints() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "reporting-endpoints",
+            reqwest::header::HeaderValue::from_static(
+                r#"csp-endpoint="https://example.com/reports""#,
+            ),
+        );
+        let issues = check_reporting("default-src 'self'; report-to csp-endpoint", &headers);
+        assert!(!issues
+            .iter()
+            .any(|i| i.message.contains("no matching group")));
+    }
 }
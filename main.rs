@@ -7,7 +7,7 @@ mod checks;
 mod output;
 
 use scanner::Scanner;
-use output::OutputFormat;
+use output::{HardenedTarget, OutputFormat};
 
 #[derive(Parser, Debug)]
 #[command(name = "CSPy")]
@@ -27,6 +27,10 @@ struct Args {
     #[arg(short, long, value_enum, default_value_t = OutputFormat::Pretty)]
     output: OutputFormat,
 
+    /// Target dialect for `--output hardened` (nginx, apache, or a tower middleware snippet)
+    #[arg(long, value_enum, default_value_t = HardenedTarget::Nginx)]
+    hardened_target: HardenedTarget,
+
     /// Save results to file
     #[arg(short = 'f', long, value_name = "FILE")]
     output_file: Option<PathBuf>,
@@ -50,6 +54,15 @@ struct Args {
     /// Custom User-Agent
     #[arg(short = 'A', long)]
     user_agent: Option<String>,
+
+    /// Fetch and scan the HTML body for Subresource Integrity coverage
+    /// (disabled by default since it requires downloading the full body)
+    #[arg(long)]
+    check_sri: bool,
+
+    /// Maximum number of targets to scan concurrently (only relevant with -i)
+    #[arg(short = 'c', long, default_value_t = 5)]
+    concurrency: usize,
 }
 
 #[tokio::main]
@@ -77,20 +90,21 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         args.redirect,
         args.max_redirects,
         args.user_agent,
+        args.check_sri,
     );
 
-    // Scan URLs
+    // Scan URLs (concurrently when there's more than one target)
     let mut all_results = Vec::new();
-    
-    for url in urls {
+
+    for (url, result) in scanner.scan_many(&urls, args.concurrency).await {
         if !args.silent {
             println!("\n{} {}", "→".cyan().bold(), url.bright_white().bold());
         }
 
-        match scanner.scan(&url).await {
+        match result {
             Ok(result) => {
                 if !args.silent {
-                    output::print_result(&result, &args.output);
+                    output::print_result(&result, &args.output, &args.hardened_target);
                 }
                 all_results.push(result);
             }
@@ -102,7 +116,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Save to file if requested
     if let Some(output_file) = args.output_file {
-        output::save_to_file(&all_results, &output_file, &args.output)?;
+        output::save_to_file(&all_results, &output_file, &args.output, &args.hardened_target)?;
         if !args.silent {
             println!("\n{} Results saved to: {}", "✓".green().bold(), output_file.display());
         }
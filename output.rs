@@ -9,16 +9,89 @@ pub enum OutputFormat {
     Pretty,
     Json,
     Minimal,
+    Hardened,
 }
 
-pub fn print_result(result: &ScanResult, format: &OutputFormat) {
+/// Which config dialect `OutputFormat::Hardened` renders for.
+#[derive(Debug, Clone, ValueEnum)]
+pub enum HardenedTarget {
+    Nginx,
+    Apache,
+    Tower,
+}
+
+/// Canonical header recommendations, keyed by the issue category that
+/// signals the header is missing or weak. Kept in sync with the values
+/// each check module's own `recommendation` text already points at.
+const RECOMMENDED_HEADERS: &[(&str, &str, &str)] = &[
+    ("HSTS", "Strict-Transport-Security", "max-age=31536000; includeSubDomains; preload"),
+    ("CSP", "Content-Security-Policy", "default-src 'self'; object-src 'none'; base-uri 'self'; upgrade-insecure-requests"),
+    ("X-Frame-Options", "X-Frame-Options", "DENY"),
+    ("X-Content-Type-Options", "X-Content-Type-Options", "nosniff"),
+    ("Referrer-Policy", "Referrer-Policy", "strict-origin-when-cross-origin"),
+    ("Permissions-Policy", "Permissions-Policy", "geolocation=(), camera=(), microphone=(), payment=(), usb=()"),
+];
+
+/// Picks the recommended `(header, value)` pairs for every category that
+/// had at least one finding in `result`, so unaffected headers aren't
+/// included in the generated config.
+fn recommended_headers_for(result: &ScanResult) -> Vec<(&'static str, &'static str)> {
+    RECOMMENDED_HEADERS
+        .iter()
+        .filter(|(category, _, _)| {
+            result.issues.iter().any(|issue| issue.category == *category)
+        })
+        .map(|(_, name, value)| (*name, *value))
+        .collect()
+}
+
+pub fn print_result(result: &ScanResult, format: &OutputFormat, hardened_target: &HardenedTarget) {
     match format {
         OutputFormat::Pretty => print_pretty(result),
         OutputFormat::Json => print_json(result),
         OutputFormat::Minimal => print_minimal(result),
+        OutputFormat::Hardened => println!("{}", generate_hardened_config(result, hardened_target)),
     }
 }
 
+/// Renders the recommended headers for `result` as copy-pasteable config
+/// in the requested dialect.
+fn generate_hardened_config(result: &ScanResult, target: &HardenedTarget) -> String {
+    let headers = recommended_headers_for(result);
+
+    if headers.is_empty() {
+        return "# No missing or weak headers detected - nothing to harden.".to_string();
+    }
+
+    let mut content = String::new();
+    content.push_str(&format!("# Hardened header config for {}\n", result.url));
+
+    match target {
+        HardenedTarget::Nginx => {
+            for (name, value) in headers {
+                content.push_str(&format!("add_header {} \"{}\" always;\n", name, value));
+            }
+        }
+        HardenedTarget::Apache => {
+            for (name, value) in headers {
+                content.push_str(&format!("Header always set {} \"{}\"\n", name, value));
+            }
+        }
+        HardenedTarget::Tower => {
+            content.push_str("use tower_http::set_header::SetResponseHeaderLayer;\nuse http::header::{HeaderName, HeaderValue};\n\n");
+            for (name, value) in headers {
+                content.push_str(&format!(
+                    "ServiceBuilder::new().layer(SetResponseHeaderLayer::overriding(\n    HeaderName::from_static(\"{}\"),\n    HeaderValue::from_static(\"{}\"),\n));\n",
+                    name.to_lowercase(),
+                    value
+                ));
+            }
+        }
+    }
+
+    content
+}
+
 fn print_pretty(result: &ScanResult) {
     println!("  {} {}", "Status:".bright_black(), result.status);
     
@@ -124,8 +197,14 @@ pub fn save_to_file(
     results: &[ScanResult],
     path: &PathBuf,
     format: &OutputFormat,
+    hardened_target: &HardenedTarget,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let content = match format {
+        OutputFormat::Hardened => results
+            .iter()
+            .map(|result| generate_hardened_config(result, hardened_target))
+            .collect::<Vec<_>>()
+            .join("\n\n"),
         OutputFormat::Json => {
             #[derive(Serialize)]
             struct Output {
@@ -1,3 +1,4 @@
+use futures::stream::{self, StreamExt};
 use reqwest::{Client, redirect::Policy};
 use std::time::Duration;
 use url::Url;
@@ -6,6 +7,7 @@ use crate::checks::{ScanResult, SecurityIssue, Severity};
 
 pub struct Scanner {
     client: Client,
+    check_sri: bool,
 }
 
 impl Scanner {
@@ -14,6 +16,7 @@ impl Scanner {
         follow_redirects: bool,
         max_redirects: usize,
         user_agent: Option<String>,
+        check_sri: bool,
     ) -> Self {
         let redirect_policy = if follow_redirects {
             Policy::limited(max_redirects)
@@ -33,19 +36,25 @@ impl Scanner {
             .build()
             .expect("Failed to create HTTP client");
 
-        Scanner { client }
+        Scanner { client, check_sri }
     }
 
     pub async fn scan(&self, target: &str) -> Result<ScanResult, Box<dyn std::error::Error>> {
         // Normalize URL
         let url = self.normalize_url(target)?;
-        
+
         // Send request
         let response = self.client.get(url.as_str()).send().await?;
-        
+
         let status = response.status().as_u16();
         let headers = response.headers().clone();
-        let final_url = response.url().to_string();
+        let final_url_parsed = response.url().clone();
+        let final_url = final_url_parsed.to_string();
+        let is_html = headers
+            .get("content-type")
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.contains("text/html"))
+            .unwrap_or(false);
 
         // Analyze headers
         let mut issues = Vec::new();
@@ -56,18 +65,40 @@ impl Scanner {
         // Check CORS
         issues.extend(crate::checks::cors::check(&headers));
         
-        // Check HSTS
-        issues.extend(crate::checks::hsts::check(&headers));
+        // Check HSTS (only probe the bare HTTP host when preload is advertised,
+        // since that's the only case the extra round-trip is needed for)
+        let advertises_preload = headers
+            .get("strict-transport-security")
+            .and_then(|v| v.to_str().ok())
+            .map(|s| s.to_lowercase().contains("preload"))
+            .unwrap_or(false);
+        let http_redirects_to_https = if advertises_preload {
+            self.check_http_redirects_to_https(&url).await
+        } else {
+            false
+        };
+        issues.extend(crate::checks::hsts::check(&headers, &url, http_redirects_to_https));
         
         // Check X-Frame-Options
         issues.extend(crate::checks::xframe::check(&headers));
         
-        // Check Cookies
-        issues.extend(crate::checks::cookies::check(&headers));
+        // Check Cookies (validated against the final, post-redirect URL -
+        // that's the host whose Set-Cookie headers these are)
+        issues.extend(crate::checks::cookies::check(&headers, &final_url_parsed));
         
         // Additional security headers
         issues.extend(self.check_additional_headers(&headers));
 
+        // Permissions-Policy / legacy Feature-Policy
+        issues.extend(crate::checks::permissions_policy::check(&headers));
+
+        // Subresource Integrity coverage (opt-in: requires downloading the body)
+        if self.check_sri && is_html {
+            if let Ok(body) = response.text().await {
+                issues.extend(crate::checks::sri::check(&body, &final_url_parsed));
+            }
+        }
+
         Ok(ScanResult {
             url: final_url,
             status,
@@ -75,6 +106,34 @@ impl Scanner {
         })
     }
 
+    /// Scans many targets concurrently over the shared client, so connection
+    /// pooling/keep-alive is preserved across hosts. Bounded by `concurrency`
+    /// in-flight requests at a time (clamped to at least 1 - `buffer_unordered(0)`
+    /// never polls its stream and would hang forever); per-target failures
+    /// are captured rather than aborting the batch, and results come back
+    /// in input order.
+    pub async fn scan_many(
+        &self,
+        targets: &[String],
+        concurrency: usize,
+    ) -> Vec<(String, Result<ScanResult, Box<dyn std::error::Error>>)> {
+        let mut indexed: Vec<(usize, String, Result<ScanResult, Box<dyn std::error::Error>>)> =
+            stream::iter(targets.iter().cloned().enumerate())
+                .map(|(index, target)| async move {
+                    let result = self.scan(&target).await;
+                    (index, target, result)
+                })
+                .buffer_unordered(concurrency.max(1))
+                .collect()
+                .await;
+
+        indexed.sort_by_key(|(index, _, _)| *index);
+        indexed
+            .into_iter()
+            .map(|(_, target, result)| (target, result))
+            .collect()
+    }
+
     fn normalize_url(&self, target: &str) -> Result<Url, Box<dyn std::error::Error>> {
         let normalized = if target.starts_with("http://") || target.starts_with("https://") {
             target.to_string()
@@ -85,6 +144,21 @@ impl Scanner {
         Ok(Url::parse(&normalized)?)
     }
 
+    /// Probes whether the bare HTTP host redirects to HTTPS, as required
+    /// for hstspreload.org submission. Best-effort: any request failure is
+    /// treated as "does not redirect" rather than aborting the scan.
+    async fn check_http_redirects_to_https(&self, url: &Url) -> bool {
+        let mut http_url = url.clone();
+        if http_url.set_scheme("http").is_err() {
+            return false;
+        }
+
+        match self.client.get(http_url.as_str()).send().await {
+            Ok(response) => response.url().scheme() == "https",
+            Err(_) => false,
+        }
+    }
+
     fn check_additional_headers(&self, headers: &reqwest::header::HeaderMap) -> Vec<SecurityIssue> {
         let mut issues = Vec::new();
 
@@ -1,14 +1,97 @@
+use super::public_suffix;
 use super::{SecurityIssue, Severity};
+use chrono::{NaiveDate, NaiveTime, TimeZone, Utc};
 use reqwest::header::HeaderMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+use url::Url;
 
-pub fn check(headers: &HeaderMap) -> Vec<SecurityIssue> {
+/// A `Set-Cookie` header parsed per RFC 6265 §4.1.1/§5.2: the leading
+/// `name=value` pair, followed by `;`-separated attributes with an
+/// optional value. Attribute names are matched case-insensitively via
+/// [`ParsedCookie::attr`]/[`ParsedCookie::has_flag`] rather than string
+/// `contains()`, so a cookie *value* containing "secure" or a `Path=/foo`
+/// can no longer masquerade as the `Secure` flag or a root path.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedCookie {
+    pub name: String,
+    pub value: String,
+    pub attributes: Vec<(String, Option<String>)>,
+}
+
+impl ParsedCookie {
+    pub fn parse(raw: &str) -> Self {
+        let mut segments = raw.split(';');
+
+        let (name, value) = match segments.next() {
+            Some(first) => match first.split_once('=') {
+                Some((n, v)) => (n.trim().to_string(), unquote(v.trim())),
+                None => (first.trim().to_string(), String::new()),
+            },
+            None => (String::new(), String::new()),
+        };
+
+        let attributes = segments
+            .map(|segment| {
+                let segment = segment.trim();
+                match segment.split_once('=') {
+                    Some((k, v)) => (k.trim().to_string(), Some(unquote(v.trim()))),
+                    None => (segment.to_string(), None),
+                }
+            })
+            .collect();
+
+        ParsedCookie { name, value, attributes }
+    }
+
+    /// True if `key` appears as an attribute, with or without a value
+    /// (covers valueless flags like `Secure`/`HttpOnly`).
+    pub fn has_flag(&self, key: &str) -> bool {
+        self.attributes.iter().any(|(k, _)| k.eq_ignore_ascii_case(key))
+    }
+
+    /// The value of attribute `key`, if it has one.
+    pub fn attr(&self, key: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(key))
+            .and_then(|(_, v)| v.as_deref())
+    }
+}
+
+fn unquote(s: &str) -> String {
+    s.strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .unwrap_or(s)
+        .to_string()
+}
+
+/// RFC 6265 §5.1.3 domain-match: `host` matches `domain` if they're
+/// identical, or `domain` is a suffix of `host` split on a label boundary
+/// and `host` isn't an IP address (IP hosts never domain-match anything
+/// but themselves).
+fn domain_matches(host: &str, domain: &str) -> bool {
+    let host = host.to_lowercase();
+    let domain = domain.to_lowercase();
+
+    if host == domain {
+        return true;
+    }
+
+    if host.parse::<std::net::IpAddr>().is_ok() {
+        return false;
+    }
+
+    host.ends_with(&format!(".{}", domain))
+}
+
+pub fn check(headers: &HeaderMap, url: &Url) -> Vec<SecurityIssue> {
     let mut issues = Vec::new();
 
-    // Get all Set-Cookie headers
-    let cookies: Vec<&str> = headers
+    let cookies: Vec<ParsedCookie> = headers
         .get_all("set-cookie")
         .iter()
         .filter_map(|v| v.to_str().ok())
+        .map(ParsedCookie::parse)
         .collect();
 
     if cookies.is_empty() {
@@ -16,11 +99,12 @@ pub fn check(headers: &HeaderMap) -> Vec<SecurityIssue> {
         return issues;
     }
 
-    for cookie in cookies {
-        let cookie_name = extract_cookie_name(cookie);
-        
+    for cookie in &cookies {
+        let cookie_name = &cookie.name;
+        let has_secure = cookie.has_flag("secure");
+
         // Check for Secure flag
-        if !cookie.to_lowercase().contains("secure") {
+        if !has_secure {
             issues.push(SecurityIssue {
                 category: "Cookie Security".to_string(),
                 severity: Severity::Medium,
@@ -30,9 +114,9 @@ pub fn check(headers: &HeaderMap) -> Vec<SecurityIssue> {
         }
 
         // Check for HttpOnly flag
-        if !cookie.to_lowercase().contains("httponly") {
+        if !cookie.has_flag("httponly") {
             // Only flag as issue for session-like cookies
-            if is_sensitive_cookie(&cookie_name) {
+            if is_sensitive_cookie(cookie_name) {
                 issues.push(SecurityIssue {
                     category: "Cookie Security".to_string(),
                     severity: Severity::Medium,
@@ -49,16 +133,29 @@ pub fn check(headers: &HeaderMap) -> Vec<SecurityIssue> {
             }
         }
 
-        // Check for SameSite
-        let samesite_info = extract_samesite(cookie);
-        match samesite_info {
+        // Check for SameSite. A cookie's Secure presence changes what
+        // SameSite=None actually means to a modern browser, so the two
+        // attributes are evaluated together rather than independently.
+        match extract_samesite(cookie) {
             SameSite::None => {
-                issues.push(SecurityIssue {
-                    category: "Cookie Security".to_string(),
-                    severity: Severity::Medium,
-                    message: format!("Cookie '{}' has SameSite=None", cookie_name),
-                    recommendation: "SameSite=None requires Secure flag and allows cross-site requests. Use Lax or Strict if possible.".to_string(),
-                });
+                if !has_secure {
+                    issues.push(SecurityIssue {
+                        category: "Cookie Security".to_string(),
+                        severity: Severity::High,
+                        message: format!(
+                            "Cookie '{}' has SameSite=None without Secure",
+                            cookie_name
+                        ),
+                        recommendation: "Chromium and Firefox reject SameSite=None cookies that lack Secure outright - add 'Secure' or the cookie will simply be dropped".to_string(),
+                    });
+                } else {
+                    issues.push(SecurityIssue {
+                        category: "Cookie Security".to_string(),
+                        severity: Severity::Medium,
+                        message: format!("Cookie '{}' has SameSite=None", cookie_name),
+                        recommendation: "SameSite=None allows cross-site requests. Use Lax or Strict unless cross-site delivery is required.".to_string(),
+                    });
+                }
             }
             SameSite::Lax => {
                 // Lax is acceptable for most use cases
@@ -70,27 +167,94 @@ pub fn check(headers: &HeaderMap) -> Vec<SecurityIssue> {
                 issues.push(SecurityIssue {
                     category: "Cookie Security".to_string(),
                     severity: Severity::Low,
-                    message: format!("Cookie '{}' missing SameSite attribute", cookie_name),
-                    recommendation: "Add 'SameSite=Lax' or 'SameSite=Strict' to prevent CSRF attacks".to_string(),
+                    message: format!(
+                        "Cookie '{}' missing SameSite attribute (browsers default to Lax)",
+                        cookie_name
+                    ),
+                    recommendation: "Add an explicit 'SameSite=Lax' or 'SameSite=Strict' if the cookie needs to be sent on cross-site navigations, since the implicit Lax default may not match intent".to_string(),
                 });
             }
         }
 
-        // Check for overly long expiration
-        if let Some(max_age) = extract_max_age(cookie) {
-            if max_age > 31536000 {  // More than 1 year
+        // Check cookie lifetime. Max-Age takes precedence over Expires per
+        // RFC 6265 §5.3, so only fall back to the parsed Expires date when
+        // Max-Age is absent or unparsable.
+        let max_age = cookie.attr("max-age").and_then(|v| v.parse::<i64>().ok());
+        let expires_lifetime = extract_expires(cookie).map(|expires_at| expires_at - unix_now());
+
+        if let Some(lifetime) = max_age.or(expires_lifetime) {
+            if lifetime > 31536000 {
+                // More than 1 year
                 issues.push(SecurityIssue {
                     category: "Cookie Security".to_string(),
                     severity: Severity::Info,
                     message: format!("Cookie '{}' has very long expiration (>1 year)", cookie_name),
                     recommendation: "Consider shorter expiration times for sensitive cookies".to_string(),
                 });
+            } else if lifetime < 0 {
+                issues.push(SecurityIssue {
+                    category: "Cookie Security".to_string(),
+                    severity: Severity::Low,
+                    message: format!("Cookie '{}' has an expiration already in the past", cookie_name),
+                    recommendation: "A Max-Age/Expires in the past deletes the cookie immediately - confirm this is an intentional deletion and not a session-handling bug".to_string(),
+                });
+            }
+        }
+
+        // Check for a Domain scoped to a public suffix - browsers silently
+        // reject such cookies, and it usually signals a misconfiguration.
+        if let Some(domain) = cookie.attr("domain") {
+            let normalized = domain.trim_start_matches('.');
+            let host = url.host_str().unwrap_or("");
+
+            if !domain_matches(host, normalized) {
+                issues.push(SecurityIssue {
+                    category: "Cookie Security".to_string(),
+                    severity: Severity::High,
+                    message: format!(
+                        "Cookie '{}' has Domain={} which does not match the scanned host '{}'",
+                        cookie_name, domain, host
+                    ),
+                    recommendation: "Browsers reject Set-Cookie responses whose Domain attribute doesn't cover the responding host - this cookie is likely being silently dropped".to_string(),
+                });
+            } else if !normalized.eq_ignore_ascii_case(host) {
+                issues.push(SecurityIssue {
+                    category: "Cookie Security".to_string(),
+                    severity: Severity::Medium,
+                    message: format!(
+                        "Cookie '{}' has Domain={} which widens its scope to all subdomains of '{}'",
+                        cookie_name, domain, normalized
+                    ),
+                    recommendation: "Omit the Domain attribute (or set it to the exact host) unless the cookie is genuinely meant to be shared across subdomains".to_string(),
+                });
+            }
+
+            if public_suffix::is_public_suffix(normalized) {
+                issues.push(SecurityIssue {
+                    category: "Cookie Security".to_string(),
+                    severity: Severity::High,
+                    message: format!(
+                        "Cookie '{}' has Domain={} which is a public suffix; browsers will reject it",
+                        cookie_name, domain
+                    ),
+                    recommendation: "Scope the cookie to a registrable domain you control, not a public suffix like '.com' or '.co.uk'".to_string(),
+                });
+            } else if public_suffix::is_one_label_below_suffix(normalized) {
+                issues.push(SecurityIssue {
+                    category: "Cookie Security".to_string(),
+                    severity: Severity::Info,
+                    message: format!(
+                        "Cookie '{}' has Domain={} which is only one label below a public suffix - an unusually broad scope",
+                        cookie_name, domain
+                    ),
+                    recommendation: "Double check this cookie is meant to apply to the entire registrable domain".to_string(),
+                });
             }
         }
 
         // Check for __Host- and __Secure- prefixes
         if cookie_name.starts_with("__Secure-") || cookie_name.starts_with("__Host-") {
-            if !cookie.to_lowercase().contains("secure") {
+            if !has_secure {
                 issues.push(SecurityIssue {
                     category: "Cookie Security".to_string(),
                     severity: Severity::High,
@@ -100,7 +264,7 @@ pub fn check(headers: &HeaderMap) -> Vec<SecurityIssue> {
             }
 
             if cookie_name.starts_with("__Host-") {
-                if cookie.to_lowercase().contains("domain=") {
+                if cookie.has_flag("domain") {
                     issues.push(SecurityIssue {
                         category: "Cookie Security".to_string(),
                         severity: Severity::High,
@@ -108,8 +272,9 @@ pub fn check(headers: &HeaderMap) -> Vec<SecurityIssue> {
                         recommendation: "__Host- cookies must NOT have Domain attribute".to_string(),
                     });
                 }
-                
-                if !cookie.to_lowercase().contains("path=/") {
+
+                // Exact match per spec, not a prefix match - `Path=/foo` must not satisfy this.
+                if cookie.attr("path") != Some("/") {
                     issues.push(SecurityIssue {
                         category: "Cookie Security".to_string(),
                         severity: Severity::High,
@@ -124,15 +289,6 @@ pub fn check(headers: &HeaderMap) -> Vec<SecurityIssue> {
     issues
 }
 
-fn extract_cookie_name(cookie: &str) -> String {
-    cookie
-        .split('=')
-        .next()
-        .unwrap_or("unknown")
-        .trim()
-        .to_string()
-}
-
 fn is_sensitive_cookie(name: &str) -> bool {
     let name_lower = name.to_lowercase();
     name_lower.contains("session")
@@ -151,40 +307,158 @@ enum SameSite {
     Missing,
 }
 
-fn extract_samesite(cookie: &str) -> SameSite {
-    let cookie_lower = cookie.to_lowercase();
-    
-    if cookie_lower.contains("samesite=none") {
-        SameSite::None
-    } else if cookie_lower.contains("samesite=lax") {
-        SameSite::Lax
-    } else if cookie_lower.contains("samesite=strict") {
-        SameSite::Strict
-    } else {
-        SameSite::Missing
+fn extract_samesite(cookie: &ParsedCookie) -> SameSite {
+    match cookie.attr("samesite").map(|v| v.to_lowercase()) {
+        Some(v) if v == "none" => SameSite::None,
+        Some(v) if v == "lax" => SameSite::Lax,
+        Some(v) if v == "strict" => SameSite::Strict,
+        _ => SameSite::Missing,
+    }
+}
+
+fn unix_now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+/// The cookie's `Expires` attribute as a Unix timestamp, or `None` if it's
+/// absent or unparsable.
+fn extract_expires(cookie: &ParsedCookie) -> Option<i64> {
+    cookie.attr("expires").and_then(parse_cookie_date)
+}
+
+fn is_cookie_date_delimiter(c: char) -> bool {
+    matches!(c as u32, 0x09 | 0x20..=0x2F | 0x3B..=0x40 | 0x5B..=0x60 | 0x7B..=0x7E)
+}
+
+const COOKIE_DATE_MONTHS: [&str; 12] = [
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+
+fn cookie_date_month(token: &str) -> Option<u32> {
+    let lower = token.to_lowercase();
+    COOKIE_DATE_MONTHS
+        .iter()
+        .position(|m| lower.starts_with(m))
+        .map(|i| i as u32 + 1)
+}
+
+fn cookie_date_time(token: &str) -> Option<(u32, u32, u32)> {
+    let parts: Vec<&str> = token.split(':').collect();
+    if parts.len() != 3 {
+        return None;
+    }
+    let is_one_or_two_digits = |s: &str| (1..=2).contains(&s.len()) && s.chars().all(|c| c.is_ascii_digit());
+    if !parts.iter().all(|p| is_one_or_two_digits(p)) {
+        return None;
+    }
+    Some((parts[0].parse().ok()?, parts[1].parse().ok()?, parts[2].parse().ok()?))
+}
+
+/// The leading run of ASCII digits in `token`, capped at `max_len` digits
+/// (and rejected if there are more, since the RFC caps day-of-month at 2
+/// digits and year at 4) - trailing non-digit characters are allowed and
+/// ignored, per the cookie-date grammar's `*OCTET` tail.
+fn leading_digit_run(token: &str, max_len: usize) -> Option<&str> {
+    let digit_len = token.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digit_len == 0 || digit_len > max_len {
+        return None;
     }
+    Some(&token[..digit_len])
 }
 
-fn extract_max_age(cookie: &str) -> Option<i64> {
-    for part in cookie.split(';') {
-        let trimmed = part.trim().to_lowercase();
-        if trimmed.starts_with("max-age") {
-            if let Some(value) = trimmed.split('=').nth(1) {
-                return value.trim().parse::<i64>().ok();
+/// Parses an RFC 6265 §5.1.1 cookie-date - the permissive grammar behind
+/// the `Expires` attribute, which tolerates two-digit years and lets
+/// day-of-month/month/year/time appear in any order (e.g. both
+/// `"Wdy, DD Mon YYYY HH:MM:SS GMT"` and `"DD-Mon-YY HH:MM:SS GMT"`
+/// parse). Returns a Unix timestamp, or `None` if the value doesn't
+/// contain all four required fields.
+fn parse_cookie_date(value: &str) -> Option<i64> {
+    let mut time = None;
+    let mut day: Option<u32> = None;
+    let mut month = None;
+    let mut year: Option<i32> = None;
+
+    for token in value.split(is_cookie_date_delimiter).filter(|t| !t.is_empty()) {
+        if time.is_none() {
+            if let Some(t) = cookie_date_time(token) {
+                time = Some(t);
+                continue;
+            }
+        }
+        if day.is_none() {
+            if let Some(digits) = leading_digit_run(token, 2) {
+                day = digits.parse().ok();
+                continue;
             }
         }
+        if month.is_none() {
+            if let Some(m) = cookie_date_month(token) {
+                month = Some(m);
+                continue;
+            }
+        }
+        if year.is_none() {
+            if let Some(digits) = leading_digit_run(token, 4) {
+                if digits.len() >= 2 {
+                    year = digits.parse().ok();
+                    continue;
+                }
+            }
+        }
+    }
+
+    let (hour, min, sec) = time?;
+    let day = day?;
+    let month = month?;
+    let mut year = year?;
+
+    if (0..=69).contains(&year) {
+        year += 2000;
+    } else if (70..=99).contains(&year) {
+        year += 1900;
+    }
+
+    if !(1..=31).contains(&day) || year < 1601 || hour > 23 || min > 59 || sec > 59 {
+        return None;
     }
-    None
+
+    let date = NaiveDate::from_ymd_opt(year, month, day)?;
+    let naive_time = NaiveTime::from_hms_opt(hour, min, sec)?;
+    Some(Utc.from_utc_datetime(&date.and_time(naive_time)).timestamp())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn test_url(host: &str) -> Url {
+        Url::parse(&format!("https://{}/", host)).unwrap()
+    }
+
+    #[test]
+    fn test_parse_name_value_and_attributes() {
+        let cookie = ParsedCookie::parse("session=abc123; Secure; HttpOnly; Path=/; SameSite=Strict");
+        assert_eq!(cookie.name, "session");
+        assert_eq!(cookie.value, "abc123");
+        assert!(cookie.has_flag("secure"));
+        assert!(cookie.has_flag("HttpOnly"));
+        assert_eq!(cookie.attr("path"), Some("/"));
+        assert_eq!(cookie.attr("samesite"), Some("Strict"));
+    }
+
     #[test]
-    fn test_extract_cookie_name() {
-        assert_eq!(extract_cookie_name("session=abc123"), "session");
-        assert_eq!(extract_cookie_name("token=xyz; Secure; HttpOnly"), "token");
+    fn test_value_containing_secure_substring_not_mistaken_for_flag() {
+        let cookie = ParsedCookie::parse("token=my-secure-value");
+        assert!(!cookie.has_flag("secure"));
+    }
+
+    #[test]
+    fn test_host_prefix_path_prefix_is_not_exact_match() {
+        let cookie = ParsedCookie::parse("__Host-id=abc; Path=/foo; Secure");
+        assert_ne!(cookie.attr("path"), Some("/"));
     }
 
     #[test]
@@ -197,9 +471,219 @@ mod tests {
 
     #[test]
     fn test_extract_samesite() {
-        assert!(matches!(extract_samesite("session=123; SameSite=Strict"), SameSite::Strict));
-        assert!(matches!(extract_samesite("session=123; SameSite=Lax"), SameSite::Lax));
-        assert!(matches!(extract_samesite("session=123; SameSite=None"), SameSite::None));
-        assert!(matches!(extract_samesite("session=123"), SameSite::Missing));
+        assert!(matches!(
+            extract_samesite(&ParsedCookie::parse("session=123; SameSite=Strict")),
+            SameSite::Strict
+        ));
+        assert!(matches!(
+            extract_samesite(&ParsedCookie::parse("session=123; SameSite=Lax")),
+            SameSite::Lax
+        ));
+        assert!(matches!(
+            extract_samesite(&ParsedCookie::parse("session=123; SameSite=None")),
+            SameSite::None
+        ));
+        assert!(matches!(
+            extract_samesite(&ParsedCookie::parse("session=123")),
+            SameSite::Missing
+        ));
+    }
+
+    #[test]
+    fn test_samesite_none_without_secure_is_high() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "set-cookie",
+            reqwest::header::HeaderValue::from_static("session=123; SameSite=None"),
+        );
+        let issues = check(&headers, &test_url("example.com"));
+        assert!(issues
+            .iter()
+            .any(|i| matches!(i.severity, Severity::High) && i.message.contains("SameSite=None")));
+    }
+
+    #[test]
+    fn test_samesite_none_with_secure_is_medium() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "set-cookie",
+            reqwest::header::HeaderValue::from_static("session=123; SameSite=None; Secure"),
+        );
+        let issues = check(&headers, &test_url("example.com"));
+        assert!(issues
+            .iter()
+            .any(|i| matches!(i.severity, Severity::Medium) && i.message.contains("SameSite=None")));
+        assert!(!issues
+            .iter()
+            .any(|i| matches!(i.severity, Severity::High) && i.message.contains("SameSite=None")));
+    }
+
+    #[test]
+    fn test_domain_scoped_to_public_suffix_is_high() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "set-cookie",
+            reqwest::header::HeaderValue::from_static("session=abc; Domain=.co.uk; Secure"),
+        );
+        let issues = check(&headers, &test_url("co.uk"));
+        assert!(issues
+            .iter()
+            .any(|i| matches!(i.severity, Severity::High) && i.message.contains("public suffix")));
+    }
+
+    #[test]
+    fn test_domain_one_label_below_suffix_is_info() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "set-cookie",
+            reqwest::header::HeaderValue::from_static("session=abc; Domain=example.co.uk; Secure"),
+        );
+        let issues = check(&headers, &test_url("example.co.uk"));
+        assert!(issues
+            .iter()
+            .any(|i| matches!(i.severity, Severity::Info) && i.message.contains("one label below")));
+    }
+
+    #[test]
+    fn test_host_prefix_with_path_foo_still_flagged() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "set-cookie",
+            reqwest::header::HeaderValue::from_static("__Host-id=abc; Secure; Path=/foo"),
+        );
+        let issues = check(&headers, &test_url("example.com"));
+        assert!(issues.iter().any(|i| i.message.contains("Path is not /")));
+    }
+
+    #[test]
+    fn test_domain_not_matching_scanned_host_is_high() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "set-cookie",
+            reqwest::header::HeaderValue::from_static("session=abc; Domain=attacker.example; Secure"),
+        );
+        let issues = check(&headers, &test_url("example.com"));
+        assert!(issues
+            .iter()
+            .any(|i| matches!(i.severity, Severity::High) && i.message.contains("does not match")));
+    }
+
+    #[test]
+    fn test_domain_matching_exact_host_is_not_flagged() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "set-cookie",
+            reqwest::header::HeaderValue::from_static("session=abc; Domain=example.com; Secure"),
+        );
+        let issues = check(&headers, &test_url("example.com"));
+        assert!(!issues.iter().any(|i| i.message.contains("does not match")));
+        assert!(!issues.iter().any(|i| i.message.contains("widens its scope")));
+    }
+
+    #[test]
+    fn test_leading_dot_domain_widening_to_parent_is_medium() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "set-cookie",
+            reqwest::header::HeaderValue::from_static("session=abc; Domain=.example.com; Secure"),
+        );
+        let issues = check(&headers, &test_url("www.example.com"));
+        assert!(issues
+            .iter()
+            .any(|i| matches!(i.severity, Severity::Medium) && i.message.contains("widens its scope")));
+    }
+
+    #[test]
+    fn test_dotless_domain_widening_to_parent_is_also_medium() {
+        // RFC 6265 §5.2.3 strips any leading dot before matching, so
+        // `Domain=example.com` (no dot) from `www.example.com` is just as
+        // scope-widening as `Domain=.example.com` and must be caught too.
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "set-cookie",
+            reqwest::header::HeaderValue::from_static("session=abc; Domain=example.com; Secure"),
+        );
+        let issues = check(&headers, &test_url("www.example.com"));
+        assert!(issues
+            .iter()
+            .any(|i| matches!(i.severity, Severity::Medium) && i.message.contains("widens its scope")));
+    }
+
+    #[test]
+    fn test_domain_matches_ip_host_only_exact() {
+        assert!(domain_matches("203.0.113.10", "203.0.113.10"));
+        assert!(!domain_matches("203.0.113.10", "113.10"));
+    }
+
+    #[test]
+    fn test_parse_cookie_date_rfc1123_format() {
+        assert_eq!(
+            parse_cookie_date("Wed, 21 Oct 2026 07:28:00 GMT"),
+            Some(1792567680)
+        );
+    }
+
+    #[test]
+    fn test_parse_cookie_date_tolerates_permuted_fields_and_two_digit_year() {
+        // Dash-separated fields with a two-digit year, and the same date with
+        // month, day, time and year in a different order, as the relaxed
+        // cookie-date grammar allows.
+        assert_eq!(
+            parse_cookie_date("21-Oct-26 07:28:00 GMT"),
+            Some(1792567680)
+        );
+        assert_eq!(
+            parse_cookie_date("Oct 21 07:28:00 26 GMT"),
+            Some(1792567680)
+        );
+    }
+
+    #[test]
+    fn test_parse_cookie_date_rejects_incomplete_date() {
+        assert_eq!(parse_cookie_date("Wed, Oct 2026 GMT"), None);
+        assert_eq!(parse_cookie_date("not a date"), None);
+    }
+
+    #[test]
+    fn test_short_max_age_takes_precedence_over_expires() {
+        // Max-Age takes precedence: a far-future Expires paired with a
+        // short Max-Age should not trip the >1-year check.
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "set-cookie",
+            reqwest::header::HeaderValue::from_static(
+                "session=abc; Max-Age=60; Expires=Wed, 21 Oct 2099 07:28:00 GMT; Secure",
+            ),
+        );
+        let issues = check(&headers, &test_url("example.com"));
+        assert!(!issues.iter().any(|i| i.message.contains("very long expiration")));
+    }
+
+    #[test]
+    fn test_far_future_expires_without_max_age_is_long_expiration() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "set-cookie",
+            reqwest::header::HeaderValue::from_static(
+                "session=abc; Expires=Wed, 21 Oct 2099 07:28:00 GMT; Secure",
+            ),
+        );
+        let issues = check(&headers, &test_url("example.com"));
+        assert!(issues.iter().any(|i| i.message.contains("very long expiration")));
+    }
+
+    #[test]
+    fn test_expires_in_the_past_is_flagged() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "set-cookie",
+            reqwest::header::HeaderValue::from_static(
+                "session=abc; Expires=Wed, 21 Oct 2015 07:28:00 GMT; Secure",
+            ),
+        );
+        let issues = check(&headers, &test_url("example.com"));
+        assert!(issues
+            .iter()
+            .any(|i| matches!(i.severity, Severity::Low) && i.message.contains("already in the past")));
     }
 }
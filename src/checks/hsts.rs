@@ -1,13 +1,68 @@
 use super::{SecurityIssue, Severity};
 use reqwest::header::HeaderMap;
+use std::time::Duration;
+use url::Url;
 
-const SIX_MONTHS_SECONDS: i64 = 15768000;  // 6 months in seconds
-const ONE_YEAR_SECONDS: i64 = 31536000;    // 1 year in seconds
+const SIX_MONTHS_SECONDS: u64 = 15768000; // 6 months in seconds
+const ONE_YEAR_SECONDS: u64 = 31536000; // 1 year in seconds
 
-pub fn check(headers: &HeaderMap) -> Vec<SecurityIssue> {
+/// A structured view of a parsed `Strict-Transport-Security` header.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hsts {
+    pub max_age: Duration,
+    pub include_subdomains: bool,
+    pub preload: bool,
+}
+
+/// Parse a raw `Strict-Transport-Security` header value per RFC 6797.
+///
+/// Directives are split on `;`, trimmed, and matched case-insensitively.
+/// `max-age` tolerates an optionally quoted value (`max-age="0"`). Returns
+/// `None` when `max-age` is absent or unparseable, since it's mandatory
+/// for the header to have any effect.
+pub fn parse(value: &str) -> Option<Hsts> {
+    let mut max_age = None;
+    let mut include_subdomains = false;
+    let mut preload = false;
+
+    for directive in value.split(';') {
+        let directive = directive.trim();
+        if directive.is_empty() {
+            continue;
+        }
+
+        let (name, arg) = match directive.split_once('=') {
+            Some((n, v)) => (n.trim(), Some(v.trim())),
+            None => (directive, None),
+        };
+
+        match name.to_lowercase().as_str() {
+            "max-age" => {
+                let raw = arg?;
+                let unquoted = raw
+                    .strip_prefix('"')
+                    .and_then(|s| s.strip_suffix('"'))
+                    .unwrap_or(raw);
+                let seconds: u64 = unquoted.parse().ok()?;
+                max_age = Some(Duration::from_secs(seconds));
+            }
+            "includesubdomains" => include_subdomains = true,
+            "preload" => preload = true,
+            _ => {} // unrecognized directive, ignore
+        }
+    }
+
+    Some(Hsts {
+        max_age: max_age?,
+        include_subdomains,
+        preload,
+    })
+}
+
+pub fn check(headers: &HeaderMap, url: &Url, http_redirects_to_https: bool) -> Vec<SecurityIssue> {
     let mut issues = Vec::new();
 
-    let hsts = match headers.get("strict-transport-security") {
+    let hsts_header = match headers.get("strict-transport-security") {
         Some(value) => match value.to_str() {
             Ok(v) => v,
             Err(_) => return issues,
@@ -23,41 +78,39 @@ pub fn check(headers: &HeaderMap) -> Vec<SecurityIssue> {
         }
     };
 
-    // Extract max-age value
-    let max_age = extract_max_age(hsts);
-
-    match max_age {
-        Some(age) => {
-            if age < SIX_MONTHS_SECONDS {
-                issues.push(SecurityIssue {
-                    category: "HSTS".to_string(),
-                    severity: Severity::Medium,
-                    message: format!("HSTS max-age is too short ({} seconds, ~{} days)", age, age / 86400),
-                    recommendation: "Increase max-age to at least 6 months (15768000 seconds) or preferably 1 year".to_string(),
-                });
-            }
-
-            if age < ONE_YEAR_SECONDS {
-                issues.push(SecurityIssue {
-                    category: "HSTS".to_string(),
-                    severity: Severity::Info,
-                    message: "HSTS max-age is less than 1 year".to_string(),
-                    recommendation: "Consider increasing to 1 year (31536000) for stronger protection".to_string(),
-                });
-            }
-        }
+    let hsts = match parse(hsts_header) {
+        Some(h) => h,
         None => {
             issues.push(SecurityIssue {
                 category: "HSTS".to_string(),
                 severity: Severity::High,
-                message: "HSTS header missing max-age directive".to_string(),
+                message: "HSTS header missing or has an unparseable max-age directive".to_string(),
                 recommendation: "Add max-age directive: max-age=31536000".to_string(),
             });
+            return issues;
         }
+    };
+
+    let age = hsts.max_age.as_secs();
+
+    if age < SIX_MONTHS_SECONDS {
+        issues.push(SecurityIssue {
+            category: "HSTS".to_string(),
+            severity: Severity::Medium,
+            message: format!("HSTS max-age is too short ({} seconds, ~{} days)", age, age / 86400),
+            recommendation: "Increase max-age to at least 6 months (15768000 seconds) or preferably 1 year".to_string(),
+        });
+    }
+    if age < ONE_YEAR_SECONDS {
+        issues.push(SecurityIssue {
+            category: "HSTS".to_string(),
+            severity: Severity::Info,
+            message: "HSTS max-age is less than 1 year".to_string(),
+            recommendation: "Consider increasing to 1 year (31536000) for stronger protection".to_string(),
+        });
     }
 
-    // Check for includeSubDomains
-    if !hsts.to_lowercase().contains("includesubdomains") {
+    if !hsts.include_subdomains {
         issues.push(SecurityIssue {
             category: "HSTS".to_string(),
             severity: Severity::Low,
@@ -66,8 +119,7 @@ pub fn check(headers: &HeaderMap) -> Vec<SecurityIssue> {
         });
     }
 
-    // Check for preload
-    if !hsts.to_lowercase().contains("preload") {
+    if !hsts.preload {
         issues.push(SecurityIssue {
             category: "HSTS".to_string(),
             severity: Severity::Info,
@@ -76,21 +128,37 @@ pub fn check(headers: &HeaderMap) -> Vec<SecurityIssue> {
         });
     }
 
-    issues
-}
+    // Consolidated preload-eligibility check: a site can advertise `preload`
+    // without actually qualifying for the browser preload lists.
+    if hsts.preload {
+        let mut reasons = Vec::new();
+        if age < ONE_YEAR_SECONDS {
+            reasons.push("max-age is below the required 1 year (31536000s)".to_string());
+        }
+        if !hsts.include_subdomains {
+            reasons.push("includeSubDomains is not set".to_string());
+        }
+        if url.scheme() != "https" {
+            reasons.push("the scanned URL is not served over HTTPS".to_string());
+        }
+        if !http_redirects_to_https {
+            reasons.push("the bare HTTP host does not redirect to HTTPS".to_string());
+        }
 
-fn extract_max_age(hsts: &str) -> Option<i64> {
-    // Find max-age directive
-    for part in hsts.split(';') {
-        let trimmed = part.trim();
-        if trimmed.to_lowercase().starts_with("max-age") {
-            // Extract the value after '='
-            if let Some(value) = trimmed.split('=').nth(1) {
-                return value.trim().parse::<i64>().ok();
-            }
+        if !reasons.is_empty() {
+            issues.push(SecurityIssue {
+                category: "HSTS".to_string(),
+                severity: Severity::Medium,
+                message: format!(
+                    "Site advertises 'preload' but does not meet hstspreload.org submission requirements: {}",
+                    reasons.join(", ")
+                ),
+                recommendation: "Fix the listed gaps before submitting to hstspreload.org, or remove 'preload' until they're met".to_string(),
+            });
         }
     }
-    None
+
+    issues
 }
 
 #[cfg(test)]
@@ -98,37 +166,108 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_extract_max_age() {
-        assert_eq!(
-            extract_max_age("max-age=31536000; includeSubDomains"),
-            Some(31536000)
-        );
-        
-        assert_eq!(
-            extract_max_age("max-age=3600"),
-            Some(3600)
-        );
-        
-        assert_eq!(
-            extract_max_age("includeSubDomains; max-age=86400; preload"),
-            Some(86400)
-        );
-        
-        assert_eq!(
-            extract_max_age("includeSubDomains"),
-            None
-        );
+    fn test_parse_basic() {
+        let hsts = parse("max-age=31536000; includeSubDomains; preload").unwrap();
+        assert_eq!(hsts.max_age, Duration::from_secs(31536000));
+        assert!(hsts.include_subdomains);
+        assert!(hsts.preload);
+    }
+
+    #[test]
+    fn test_parse_quoted_max_age() {
+        let hsts = parse(r#"max-age="0""#).unwrap();
+        assert_eq!(hsts.max_age, Duration::from_secs(0));
+        assert!(!hsts.include_subdomains);
     }
 
     #[test]
-    fn test_short_max_age() {
-        let mut headers = reqwest::header::HeaderMap::new();
+    fn test_parse_directive_order_independent() {
+        let hsts = parse("includeSubDomains; max-age=86400; preload").unwrap();
+        assert_eq!(hsts.max_age, Duration::from_secs(86400));
+        assert!(hsts.include_subdomains);
+        assert!(hsts.preload);
+    }
+
+    #[test]
+    fn test_parse_missing_max_age() {
+        assert!(parse("includeSubDomains").is_none());
+    }
+
+    #[test]
+    fn test_parse_malformed_max_age() {
+        assert!(parse("max-age=notanumber").is_none());
+    }
+
+    fn https_url() -> Url {
+        Url::parse("https://example.com/").unwrap()
+    }
+
+    fn headers_with_hsts(value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
         headers.insert(
             "strict-transport-security",
-            reqwest::header::HeaderValue::from_static("max-age=3600")
+            reqwest::header::HeaderValue::from_str(value).unwrap(),
         );
-        
-        let issues = check(&headers);
-        assert!(issues.iter().any(|i| i.message.contains("too short")));
+        headers
+    }
+
+    #[test]
+    fn test_check_missing_header_is_medium() {
+        let issues = check(&HeaderMap::new(), &https_url(), false);
+        assert!(issues
+            .iter()
+            .any(|i| matches!(i.severity, Severity::Medium) && i.message.contains("Missing")));
+    }
+
+    #[test]
+    fn test_check_short_max_age_flags_both_medium_and_info() {
+        let headers = headers_with_hsts("max-age=86400; includeSubDomains");
+        let issues = check(&headers, &https_url(), false);
+        assert!(issues
+            .iter()
+            .any(|i| matches!(i.severity, Severity::Medium) && i.message.contains("too short")));
+        assert!(issues
+            .iter()
+            .any(|i| matches!(i.severity, Severity::Info) && i.message.contains("less than 1 year")));
+    }
+
+    #[test]
+    fn test_check_missing_include_subdomains_and_preload() {
+        let headers = headers_with_hsts("max-age=31536000");
+        let issues = check(&headers, &https_url(), false);
+        assert!(issues.iter().any(|i| i.message.contains("includeSubDomains")));
+        assert!(issues.iter().any(|i| i.message.contains("missing 'preload'")));
+    }
+
+    #[test]
+    fn test_check_preload_advertised_but_ineligible_lists_every_gap() {
+        let headers = headers_with_hsts("max-age=86400; preload");
+        let issues = check(&headers, &https_url(), false);
+        let consolidated = issues
+            .iter()
+            .find(|i| i.message.contains("does not meet hstspreload.org submission requirements"))
+            .expect("expected a consolidated preload-eligibility issue");
+        assert!(matches!(consolidated.severity, Severity::Medium));
+        assert!(consolidated.message.contains("max-age is below the required 1 year"));
+        assert!(consolidated.message.contains("includeSubDomains is not set"));
+        assert!(consolidated.message.contains("bare HTTP host does not redirect to HTTPS"));
+    }
+
+    #[test]
+    fn test_check_preload_eligible_site_has_no_consolidated_issue() {
+        let headers = headers_with_hsts("max-age=31536000; includeSubDomains; preload");
+        let issues = check(&headers, &https_url(), true);
+        assert!(!issues
+            .iter()
+            .any(|i| i.message.contains("does not meet hstspreload.org submission requirements")));
+    }
+
+    #[test]
+    fn test_check_preload_not_advertised_skips_eligibility_check() {
+        let headers = headers_with_hsts("max-age=86400");
+        let issues = check(&headers, &https_url(), false);
+        assert!(!issues
+            .iter()
+            .any(|i| i.message.contains("does not meet hstspreload.org submission requirements")));
     }
 }
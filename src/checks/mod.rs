@@ -5,6 +5,9 @@ pub mod cors;
 pub mod hsts;
 pub mod xframe;
 pub mod cookies;
+pub mod permissions_policy;
+pub mod public_suffix;
+pub mod sri;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum Severity {
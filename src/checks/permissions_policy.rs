@@ -0,0 +1,216 @@
+use super::{SecurityIssue, Severity};
+use reqwest::header::HeaderMap;
+use std::collections::HashMap;
+
+/// Browser features whose access is worth scrutinizing when left wide
+/// open. Not exhaustive, but covers the features most commonly abused
+/// once granted (tracking, payment fraud, device fingerprinting).
+const POWERFUL_FEATURES: &[&str] = &[
+    "camera",
+    "microphone",
+    "geolocation",
+    "payment",
+    "usb",
+    "fullscreen",
+    "midi",
+    "serial",
+    "bluetooth",
+];
+
+/// A directive's parsed allowlist.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Allowlist {
+    /// `()` - feature disabled everywhere.
+    None,
+    /// `*` - feature allowed for any origin.
+    Wildcard,
+    /// `(self)`, `(self "https://example.com")`, etc.
+    Origins(Vec<String>),
+}
+
+pub type PermissionsPolicy = HashMap<String, Allowlist>;
+
+/// Parse a `Permissions-Policy` header into a feature -> allowlist map.
+///
+/// The structured-field grammar is a comma-separated list of
+/// `feature=allowlist` entries, where `allowlist` is `*`, `()`, or a
+/// parenthesized, space/`"`-delimited list of origins (`self` included).
+pub fn parse(value: &str) -> PermissionsPolicy {
+    let mut policy = PermissionsPolicy::new();
+
+    for entry in value.split(',') {
+        let entry = entry.trim();
+        let Some((feature, allowlist_raw)) = entry.split_once('=') else {
+            continue;
+        };
+        let feature = feature.trim().to_lowercase();
+        let allowlist_raw = allowlist_raw.trim();
+
+        let allowlist = if allowlist_raw == "*" {
+            Allowlist::Wildcard
+        } else if allowlist_raw == "()" {
+            Allowlist::None
+        } else {
+            let inner = allowlist_raw
+                .trim_start_matches('(')
+                .trim_end_matches(')');
+            let origins: Vec<String> = inner
+                .split_whitespace()
+                .map(|tok| tok.trim_matches('"').to_string())
+                .collect();
+            if origins.is_empty() {
+                Allowlist::None
+            } else if origins.iter().any(|o| o == "*") {
+                Allowlist::Wildcard
+            } else {
+                Allowlist::Origins(origins)
+            }
+        };
+
+        policy.insert(feature, allowlist);
+    }
+
+    policy
+}
+
+/// Parse the legacy `Feature-Policy` header, which uses
+/// `feature 'self'|'none'|<origin> ...` separated by `;` instead of the
+/// `feature=()` structured-field syntax.
+pub fn parse_legacy(value: &str) -> HashMap<String, Vec<String>> {
+    let mut policy = HashMap::new();
+
+    for directive in value.split(';') {
+        let directive = directive.trim();
+        if directive.is_empty() {
+            continue;
+        }
+
+        let mut parts = directive.split_whitespace();
+        let Some(feature) = parts.next() else {
+            continue;
+        };
+        let allowlist: Vec<String> = parts.map(|tok| tok.trim_matches('\'').to_string()).collect();
+        policy.insert(feature.to_lowercase(), allowlist);
+    }
+
+    policy
+}
+
+pub fn check(headers: &HeaderMap) -> Vec<SecurityIssue> {
+    let mut issues = Vec::new();
+
+    let permissions_policy = headers
+        .get("permissions-policy")
+        .and_then(|v| v.to_str().ok())
+        .map(parse);
+
+    let feature_policy = headers
+        .get("feature-policy")
+        .and_then(|v| v.to_str().ok())
+        .map(parse_legacy);
+
+    if let Some(policy) = &permissions_policy {
+        for feature in POWERFUL_FEATURES {
+            match policy.get(*feature) {
+                Some(Allowlist::Wildcard) => {
+                    issues.push(SecurityIssue {
+                        category: "Permissions-Policy".to_string(),
+                        severity: Severity::Medium,
+                        message: format!("Permissions-Policy allows '{}' for any origin (*)", feature),
+                        recommendation: format!("Restrict '{}' to '()' or '(self)' unless every embedded origin needs it", feature),
+                    });
+                }
+                Some(Allowlist::Origins(origins)) if origins.iter().any(|o| o != "self") => {
+                    issues.push(SecurityIssue {
+                        category: "Permissions-Policy".to_string(),
+                        severity: Severity::Low,
+                        message: format!(
+                            "Permissions-Policy allows '{}' for third-party origins: {}",
+                            feature,
+                            origins.join(", ")
+                        ),
+                        recommendation: format!("Narrow '{}' to '(self)' unless those specific origins require it", feature),
+                    });
+                }
+                _ => {}
+            }
+        }
+    }
+
+    if let Some(legacy) = &feature_policy {
+        issues.push(SecurityIssue {
+            category: "Permissions-Policy".to_string(),
+            severity: Severity::Low,
+            message: "Legacy Feature-Policy header is present".to_string(),
+            recommendation: "Migrate to Permissions-Policy; Feature-Policy is deprecated and unsupported in most current browsers".to_string(),
+        });
+
+        if let Some(policy) = &permissions_policy {
+            for (feature, legacy_allowlist) in legacy {
+                let legacy_allows_none = legacy_allowlist.iter().any(|o| o == "none");
+                let modern_blocks = matches!(policy.get(feature), Some(Allowlist::None) | None);
+                if legacy_allows_none != modern_blocks {
+                    issues.push(SecurityIssue {
+                        category: "Permissions-Policy".to_string(),
+                        severity: Severity::Medium,
+                        message: format!(
+                            "Feature-Policy and Permissions-Policy disagree on '{}'",
+                            feature
+                        ),
+                        recommendation: "Keep legacy Feature-Policy and Permissions-Policy in sync, or drop Feature-Policy entirely".to_string(),
+                    });
+                }
+            }
+        }
+    }
+
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_wildcard_and_none() {
+        let policy = parse("camera=*, microphone=()");
+        assert_eq!(policy.get("camera"), Some(&Allowlist::Wildcard));
+        assert_eq!(policy.get("microphone"), Some(&Allowlist::None));
+    }
+
+    #[test]
+    fn test_parse_origin_list() {
+        let policy = parse(r#"geolocation=(self "https://example.com")"#);
+        assert_eq!(
+            policy.get("geolocation"),
+            Some(&Allowlist::Origins(vec![
+                "self".to_string(),
+                "https://example.com".to_string()
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_wildcard_powerful_feature_flagged() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "permissions-policy",
+            reqwest::header::HeaderValue::from_static("camera=*"),
+        );
+        let issues = check(&headers);
+        assert!(issues
+            .iter()
+            .any(|i| matches!(i.severity, Severity::Medium) && i.message.contains("camera")));
+    }
+
+    #[test]
+    fn test_legacy_header_flagged() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "feature-policy",
+            reqwest::header::HeaderValue::from_static("geolocation 'self'"),
+        );
+        let issues = check(&headers);
+        assert!(issues.iter().any(|i| i.message.contains("Legacy Feature-Policy")));
+    }
+}
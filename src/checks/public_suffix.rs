@@ -0,0 +1,183 @@
+//! Public Suffix List lookup, embedded at build time so cookie
+//! domain-scope checks work offline. `public_suffix_list.dat` is a curated
+//! excerpt of the Mozilla PSL (publicsuffix.org) in its native rule syntax
+//! - it does not carry the full ~9,000-rule upstream file, so explicit
+//! coverage beyond it relies on the algorithm's own prevailing "*" rule
+//! (see [`matched_suffix_len`]): an unlisted TLD still registers as a
+//! suffix because unmatched domains fall back to their last label, which
+//! is how the real PSL algorithm (and real browsers) treat any suffix with
+//! no explicit rule. Multi-label suffixes that aren't simply "the TLD"
+//! (`co.uk`, `github.io`, `blogspot.com`, ...) still need an explicit line
+//! in the data file; extend it from upstream `effective_tld_names.dat` as
+//! gaps are found.
+
+const PSL_DATA: &str = include_str!("public_suffix_list.dat");
+
+struct Rule {
+    /// Labels in left-to-right order, e.g. `["co", "uk"]` for `co.uk`.
+    labels: Vec<String>,
+    is_exception: bool,
+}
+
+fn load_rules() -> Vec<Rule> {
+    PSL_DATA
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && !line.starts_with("//"))
+        .map(|line| {
+            let (is_exception, rule) = match line.strip_prefix('!') {
+                Some(rest) => (true, rest),
+                None => (false, line),
+            };
+            Rule {
+                labels: rule.split('.').map(|s| s.to_lowercase()).collect(),
+                is_exception,
+            }
+        })
+        .collect()
+}
+
+/// Returns how many of `domain_labels` (right-to-left) are covered by the
+/// longest matching rule, honoring wildcard (`*`) and exception (`!`)
+/// rules per the PSL algorithm. Returns 0 if no *explicit* rule matches;
+/// callers apply the algorithm's implicit prevailing rule (the last label
+/// alone) on top of that, same as this function's caller does.
+fn matched_suffix_len(domain_labels_rev: &[&str], rules: &[Rule]) -> usize {
+    let mut best_len = 0usize;
+    let mut best_is_exception = false;
+
+    for rule in rules {
+        if rule.labels.len() > domain_labels_rev.len() {
+            continue;
+        }
+
+        let matches = rule
+            .labels
+            .iter()
+            .rev()
+            .zip(domain_labels_rev.iter())
+            .all(|(rule_label, domain_label)| rule_label == "*" || rule_label.eq_ignore_ascii_case(domain_label));
+
+        if matches && rule.labels.len() >= best_len {
+            best_len = rule.labels.len();
+            best_is_exception = rule.is_exception;
+        }
+    }
+
+    // An exception rule carves one label back out of the suffix it would
+    // otherwise complete, e.g. `!city.kawasaki.jp` means `kawasaki.jp` is
+    // the suffix, not `city.kawasaki.jp`.
+    if best_is_exception {
+        best_len.saturating_sub(1)
+    } else {
+        best_len
+    }
+}
+
+/// Normalizes `domain` (strip leading dot, lowercase) and reports how many
+/// of its labels, counted from the right, make up its public suffix.
+/// `None` only for an empty domain - an unlisted TLD still reports `1`,
+/// since the PSL's prevailing rule ("*") treats any domain with no
+/// explicit match as having its last label alone be the public suffix.
+pub fn public_suffix_label_count(domain: &str) -> Option<usize> {
+    let domain = domain.trim_start_matches('.').to_lowercase();
+    if domain.is_empty() {
+        return None;
+    }
+
+    let labels: Vec<&str> = domain.split('.').collect();
+    let labels_rev: Vec<&str> = labels.iter().rev().copied().collect();
+    let rules = load_rules();
+
+    match matched_suffix_len(&labels_rev, &rules) {
+        0 => Some(1),
+        len => Some(len),
+    }
+}
+
+/// True if `domain` is *exactly* a public suffix (e.g. `co.uk`, `.com`),
+/// meaning browsers will refuse to let a cookie scope to it.
+pub fn is_public_suffix(domain: &str) -> bool {
+    let domain = domain.trim_start_matches('.').to_lowercase();
+    let label_count = domain.split('.').count();
+    public_suffix_label_count(&domain) == Some(label_count)
+}
+
+/// True if `domain` is exactly one label below its public suffix *and*
+/// that suffix is itself multi-label (e.g. `example.co.uk`, `example.github.io`) -
+/// a legitimate registrable domain but still an unusually broad cookie
+/// scope worth a quieter note. Deliberately excludes ordinary
+/// single-label-TLD domains like `example.com`: under the prevailing "*"
+/// rule those are *always* one label below their suffix, so gating on it
+/// alone would flag nearly every domain on the internet.
+pub fn is_one_label_below_suffix(domain: &str) -> bool {
+    let domain = domain.trim_start_matches('.').to_lowercase();
+    let label_count = domain.split('.').count();
+    if label_count <= 1 {
+        return false;
+    }
+
+    match public_suffix_label_count(&domain) {
+        Some(suffix_len) if suffix_len >= 2 => suffix_len == label_count - 1,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exact_public_suffix() {
+        assert!(is_public_suffix("co.uk"));
+        assert!(is_public_suffix(".com"));
+        assert!(is_public_suffix("herokuapp.com"));
+    }
+
+    #[test]
+    fn test_registrable_domain_is_not_a_suffix() {
+        assert!(!is_public_suffix("example.co.uk"));
+        assert!(!is_public_suffix("example.com"));
+    }
+
+    #[test]
+    fn test_one_label_below_suffix() {
+        assert!(is_one_label_below_suffix("example.co.uk"));
+        assert!(!is_one_label_below_suffix("co.uk"));
+    }
+
+    #[test]
+    fn test_wildcard_and_exception_rule() {
+        // *.kawasaki.jp makes city.kawasaki.jp look like a suffix, but the
+        // exception rule carves it back out.
+        assert!(!is_public_suffix("city.kawasaki.jp"));
+        assert!(is_public_suffix("asakusa.kawasaki.jp"));
+    }
+
+    #[test]
+    fn test_unrecognized_domain_is_not_a_suffix() {
+        assert!(!is_public_suffix("totally-unknown-tld.zzz"));
+    }
+
+    #[test]
+    fn test_unlisted_tld_is_still_a_suffix_via_prevailing_rule() {
+        // No explicit rule for this made-up TLD, but the PSL's prevailing
+        // "*" rule means an unlisted TLD is still a public suffix on its own.
+        assert!(is_public_suffix("zzz"));
+        assert!(is_public_suffix(".zzz"));
+    }
+
+    #[test]
+    fn test_ordinary_dot_com_domain_is_not_one_label_below_suffix() {
+        // example.com is one label below ".com", but ".com" isn't a
+        // multi-label suffix, so this shouldn't fire - it would otherwise
+        // flag nearly every plain .com/.org/.net domain.
+        assert!(!is_one_label_below_suffix("example.com"));
+        assert!(!is_one_label_below_suffix("example.org"));
+    }
+
+    #[test]
+    fn test_multi_label_suffix_still_flagged_one_label_below() {
+        assert!(is_one_label_below_suffix("example.github.io"));
+    }
+}
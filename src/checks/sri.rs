@@ -0,0 +1,109 @@
+use super::{SecurityIssue, Severity};
+use scraper::{Html, Selector};
+use url::Url;
+
+/// Scans an HTML document for cross-origin `<script>`/`<link rel="stylesheet">`
+/// tags that lack Subresource Integrity protection, so a compromised CDN
+/// can't silently inject malicious code into the page.
+pub fn check(body: &str, page_url: &Url) -> Vec<SecurityIssue> {
+    let mut issues = Vec::new();
+    let document = Html::parse_document(body);
+
+    let script_selector = Selector::parse("script[src]").expect("static selector is valid");
+    let link_selector =
+        Selector::parse("link[rel=\"stylesheet\"][href]").expect("static selector is valid");
+
+    for element in document.select(&script_selector) {
+        let Some(src) = element.value().attr("src") else {
+            continue;
+        };
+        check_resource(page_url, src, element.value().attr("integrity"), element.value().attr("crossorigin"), "script", &mut issues);
+    }
+
+    for element in document.select(&link_selector) {
+        let Some(href) = element.value().attr("href") else {
+            continue;
+        };
+        check_resource(page_url, href, element.value().attr("integrity"), element.value().attr("crossorigin"), "stylesheet", &mut issues);
+    }
+
+    issues
+}
+
+fn check_resource(
+    page_url: &Url,
+    resource_url: &str,
+    integrity: Option<&str>,
+    crossorigin: Option<&str>,
+    kind: &str,
+    issues: &mut Vec<SecurityIssue>,
+) {
+    let Ok(resolved) = page_url.join(resource_url) else {
+        return;
+    };
+
+    if !is_cross_origin(page_url, &resolved) {
+        return;
+    }
+
+    match integrity {
+        None => {
+            issues.push(SecurityIssue {
+                category: "SRI".to_string(),
+                severity: Severity::Medium,
+                message: format!("Cross-origin {} '{}' has no integrity attribute", kind, resolved),
+                recommendation: "Add an 'integrity' attribute with a sha384- hash so a compromised CDN can't inject malicious code".to_string(),
+            });
+        }
+        Some(_) if crossorigin.is_none() => {
+            issues.push(SecurityIssue {
+                category: "SRI".to_string(),
+                severity: Severity::Low,
+                message: format!("Cross-origin {} '{}' has integrity but no crossorigin attribute", kind, resolved),
+                recommendation: "Add 'crossorigin=\"anonymous\"' alongside 'integrity'; browsers refuse to apply SRI without it".to_string(),
+            });
+        }
+        Some(_) => {}
+    }
+}
+
+fn is_cross_origin(page_url: &Url, resource_url: &Url) -> bool {
+    page_url.host_str() != resource_url.host_str()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cross_origin_script_missing_integrity() {
+        let page_url = Url::parse("https://example.com/").unwrap();
+        let body = r#"<html><head><script src="https://cdn.example.net/lib.js"></script></head></html>"#;
+        let issues = check(body, &page_url);
+        assert!(issues.iter().any(|i| i.category == "SRI" && matches!(i.severity, Severity::Medium)));
+    }
+
+    #[test]
+    fn test_integrity_without_crossorigin() {
+        let page_url = Url::parse("https://example.com/").unwrap();
+        let body = r#"<html><head><script src="https://cdn.example.net/lib.js" integrity="sha384-abc"></script></head></html>"#;
+        let issues = check(body, &page_url);
+        assert!(issues.iter().any(|i| i.category == "SRI" && matches!(i.severity, Severity::Low)));
+    }
+
+    #[test]
+    fn test_same_origin_script_not_flagged() {
+        let page_url = Url::parse("https://example.com/").unwrap();
+        let body = r#"<html><head><script src="/lib.js"></script></head></html>"#;
+        let issues = check(body, &page_url);
+        assert!(issues.is_empty());
+    }
+
+    #[test]
+    fn test_fully_covered_resource_not_flagged() {
+        let page_url = Url::parse("https://example.com/").unwrap();
+        let body = r#"<html><head><link rel="stylesheet" href="https://cdn.example.net/style.css" integrity="sha384-abc" crossorigin="anonymous"></head></html>"#;
+        let issues = check(body, &page_url);
+        assert!(issues.is_empty());
+    }
+}